@@ -1,6 +1,7 @@
 use std::{collections::HashMap, hash::Hash};
 
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct TrieNode<K, V>
 where
     K: Hash + Eq + Default,
@@ -10,12 +11,43 @@ where
     value: Option<V>,
 }
 
+impl<K, V> TrieNode<K, V>
+where
+    K: Hash + Eq + Default,
+    V: Hash + Eq + Clone + Default,
+{
+    /// Removes the value at the end of `parts`, then reports whether this node became an
+    /// empty, valueless leaf so its caller can prune it from `children` in turn.
+    fn remove(&mut self, mut parts: impl Iterator<Item = K>) -> (Option<V>, bool) {
+        match parts.next() {
+            None => {
+                let value = self.value.take();
+                (value, self.children.is_empty())
+            }
+            Some(part) => {
+                let Some(child) = self.children.get_mut(&part) else {
+                    return (None, false);
+                };
+                let (value, child_is_empty) = child.remove(parts);
+                if child_is_empty {
+                    self.children.remove(&part);
+                }
+                (value, self.value.is_none() && self.children.is_empty())
+            }
+        }
+    }
+}
+
+/// With the `serde` feature enabled, a `Trie` (de)serializes as its root node (a nested map of
+/// children plus an optional value, recursively) alongside its optional wildcard key.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Trie<K, V>
 where
     K: Hash + Eq + Default,
     V: Hash + Eq + Clone + Default,
 {
     root: TrieNode<K, V>,
+    wildcard: Option<K>,
 }
 
 impl<K, V> Trie<K, V>
@@ -27,9 +59,21 @@ where
     pub fn new() -> Self {
         Trie {
             root: TrieNode::default(),
+            wildcard: None,
         }
     }
 
+    /// ### About
+    /// Designates `wildcard` as a sentinel key value: [`Trie::best_match`] and
+    /// [`Trie::get_with_params`] fall back to a child stored under this key when no exact
+    /// child matches, enabling catch-all route segments (e.g. a `:id` path component).
+    /// Other lookups (`get`, `contains`, `find_prefixes`, `predictive_search`, `seek_prefix`)
+    /// match key parts literally.
+    pub fn with_wildcard(mut self, wildcard: K) -> Self {
+        self.wildcard = Some(wildcard);
+        self
+    }
+
     /// Get a copy of the value associated with the key in O(len(key)) time
     pub fn get<I>(&self, key: I) -> Option<V>
     where
@@ -48,7 +92,9 @@ where
     }
 
     /// ### About
-    /// Finds the value of the longest entry with prefix key
+    /// Finds the value of the longest entry with prefix key. If a wildcard was set via
+    /// [`Trie::with_wildcard`], a part that has no exact child falls back to the wildcard
+    /// child, so descent can continue through catch-all route segments.
     ///
     /// ### Example
     /// Assume trie contains the following keys and values of the form (key) -> value
@@ -63,7 +109,12 @@ where
         let mut cur: &TrieNode<K, V> = &self.root;
         let mut cur_match = None;
         for part in key {
-            if let Some(v) = cur.children.get(&part) {
+            let next = cur.children.get(&part).or_else(|| {
+                self.wildcard
+                    .as_ref()
+                    .and_then(|wildcard| cur.children.get(wildcard))
+            });
+            if let Some(v) = next {
                 cur = v;
                 if let Some(new_match) = cur.value.as_ref() {
                     cur_match.replace(new_match.clone());
@@ -75,6 +126,59 @@ where
         cur_match
     }
 
+    /// ### About
+    /// Collects the value of every node visited while descending along key's path, in root-to-leaf order
+    ///
+    /// ### Example
+    /// Assume trie contains the following keys and values of the form (key) -> value
+    /// - (four, score, and) -> seven
+    /// - (four, score, and, seven) -> years
+    ///
+    /// The query `find_prefixes ["four", "score", "and", "seven", "years", "ago"]` will return `vec![seven, years]`
+    pub fn find_prefixes<I>(&self, key: I) -> Vec<V>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut cur: &TrieNode<K, V> = &self.root;
+        let mut matches = Vec::new();
+        for part in key {
+            if let Some(v) = cur.children.get(&part) {
+                cur = v;
+                if let Some(value) = cur.value.as_ref() {
+                    matches.push(value.clone());
+                }
+            } else {
+                break;
+            }
+        }
+        matches
+    }
+
+    /// Like [`Trie::find_prefixes`], but records the number of parts consumed so far each time
+    /// a valued node is reached, instead of cloning the value itself. Shared by
+    /// [`SuffixTrie::query_suffixes`] so it can walk a `Trie` without reaching into its private
+    /// fields.
+    pub(crate) fn traverse_lengths<I>(&self, key: I) -> Vec<usize>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut cur: &TrieNode<K, V> = &self.root;
+        let mut lengths = Vec::new();
+        let mut consumed = 0;
+        for part in key {
+            if let Some(v) = cur.children.get(&part) {
+                cur = v;
+                consumed += 1;
+                if cur.value.is_some() {
+                    lengths.push(consumed);
+                }
+            } else {
+                break;
+            }
+        }
+        lengths
+    }
+
     /// Inserts key and value into Trie, overriding any previous value
     pub fn insert<I>(&mut self, key: I, value: V)
     where
@@ -87,6 +191,18 @@ where
         cur.value = Some(value);
     }
 
+    /// ### About
+    /// Removes the value associated with key, returning the old value if one was present, and
+    /// prunes any now-empty valueless nodes back up to the root. This keeps the invariant that
+    /// no valueless leaf nodes remain after a removal, so memory is reclaimed and
+    /// [`Trie::predictive_search`]/[`Trie::iter`] don't waste time walking dead branches.
+    pub fn remove<I>(&mut self, key: I) -> Option<V>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        self.root.remove(key.into_iter()).0
+    }
+
     /// Helper function to traverse the Trie
     fn traverse<I>(&self, key: I) -> Option<&TrieNode<K, V>>
     where
@@ -103,6 +219,245 @@ where
     }
 }
 
+impl<K, V> Default for Trie<K, V>
+where
+    K: Hash + Eq + Default,
+    V: Hash + Eq + Clone + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Trie<K, V>
+where
+    K: Hash + Eq + Default + Clone,
+    V: Hash + Eq + Clone + Default,
+{
+    /// ### About
+    /// Lists every (key, value) pair stored in the subtree rooted at prefix, including the
+    /// prefix's own value if present. Useful for autocomplete-style lookups.
+    ///
+    /// ### Example
+    /// Assume trie contains the following keys and values of the form (key) -> value
+    /// - (four, score, and) -> seven
+    /// - (four, score, and, seven) -> years
+    ///
+    /// The query `predictive_search ["four", "score", "and"]` will return
+    /// `vec![(["four", "score", "and"], seven), (["four", "score", "and", "seven"], years)]`
+    /// in some order
+    pub fn predictive_search<I>(&self, prefix: I) -> Vec<(Vec<K>, V)>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let prefix_parts: Vec<K> = prefix.into_iter().collect();
+        let Some(start) = self.traverse(prefix_parts.clone()) else {
+            return Vec::new();
+        };
+
+        let mut results = Vec::new();
+        let mut stack: Vec<(&TrieNode<K, V>, Vec<K>)> = vec![(start, prefix_parts)];
+        while let Some((node, key)) = stack.pop() {
+            if let Some(value) = node.value.as_ref() {
+                results.push((key.clone(), value.clone()));
+            }
+            for (part, child) in node.children.iter() {
+                let mut child_key = key.clone();
+                child_key.push(part.clone());
+                stack.push((child, child_key));
+            }
+        }
+        results
+    }
+
+    /// ### About
+    /// Like [`Trie::best_match`], but also returns the concrete key parts captured by wildcard
+    /// positions along the best-matching path, so a matched route can report its bound
+    /// parameters. Requires a wildcard to have been set via [`Trie::with_wildcard`] for any
+    /// captures to occur.
+    pub fn get_with_params<I>(&self, key: I) -> Option<(V, Vec<K>)>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut cur: &TrieNode<K, V> = &self.root;
+        let mut cur_match: Option<(V, Vec<K>)> = None;
+        let mut params: Vec<K> = Vec::new();
+        for part in key {
+            let next = match cur.children.get(&part) {
+                Some(v) => v,
+                None => match self
+                    .wildcard
+                    .as_ref()
+                    .and_then(|wildcard| cur.children.get(wildcard))
+                {
+                    Some(v) => {
+                        params.push(part);
+                        v
+                    }
+                    None => break,
+                },
+            };
+            cur = next;
+            if let Some(value) = cur.value.as_ref() {
+                cur_match.replace((value.clone(), params.clone()));
+            }
+        }
+        cur_match
+    }
+
+    /// ### About
+    /// Returns an iterator over every `(key, value)` pair in the Trie, without materializing
+    /// them all into a `Vec` up front. Traversal order is unspecified.
+    pub fn iter(&self) -> TrieIter<'_, K, V> {
+        TrieIter {
+            stack: vec![Crumb {
+                node: &self.root,
+                key: Vec::new(),
+                children: self.root.children.iter(),
+                emitted: false,
+            }],
+        }
+    }
+
+    /// ### About
+    /// Like [`Trie::iter`], but positions the iterator so it only yields keys starting with
+    /// `prefix`, bounding traversal to that subtree.
+    pub fn seek_prefix<I>(&self, prefix: I) -> TrieIter<'_, K, V>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let prefix_parts: Vec<K> = prefix.into_iter().collect();
+        match self.traverse(prefix_parts.clone()) {
+            Some(node) => TrieIter {
+                stack: vec![Crumb {
+                    node,
+                    key: prefix_parts,
+                    children: node.children.iter(),
+                    emitted: false,
+                }],
+            },
+            None => TrieIter { stack: Vec::new() },
+        }
+    }
+}
+
+/// A single frame of a [`TrieIter`]'s traversal stack: the node it's sitting on, the key
+/// accumulated to reach it, a cursor over its remaining children, and whether its own value
+/// has already been emitted.
+struct Crumb<'a, K, V>
+where
+    K: Hash + Eq + Default,
+    V: Hash + Eq + Clone + Default,
+{
+    node: &'a TrieNode<K, V>,
+    key: Vec<K>,
+    children: std::collections::hash_map::Iter<'a, K, TrieNode<K, V>>,
+    emitted: bool,
+}
+
+/// An iterator over `(Vec<K>, V)` pairs produced by [`Trie::iter`] or [`Trie::seek_prefix`].
+///
+/// Modeled as a stack of "crumbs": on each `next()` call the top crumb emits its own value
+/// (the first time it's visited), then pushes a crumb for its next unvisited child, descending
+/// depth-first until the stack (and thus the subtree under consideration) is exhausted.
+pub struct TrieIter<'a, K, V>
+where
+    K: Hash + Eq + Default + Clone,
+    V: Hash + Eq + Clone + Default,
+{
+    stack: Vec<Crumb<'a, K, V>>,
+}
+
+impl<'a, K, V> Iterator for TrieIter<'a, K, V>
+where
+    K: Hash + Eq + Default + Clone,
+    V: Hash + Eq + Clone + Default,
+{
+    type Item = (Vec<K>, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(crumb) = self.stack.last_mut() {
+            if !crumb.emitted {
+                crumb.emitted = true;
+                if let Some(value) = crumb.node.value.clone() {
+                    return Some((crumb.key.clone(), value));
+                }
+            }
+            match crumb.children.next() {
+                Some((part, child)) => {
+                    let mut child_key = crumb.key.clone();
+                    child_key.push(part.clone());
+                    self.stack.push(Crumb {
+                        node: child,
+                        key: child_key,
+                        children: child.children.iter(),
+                        emitted: false,
+                    });
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
+/// A [`Trie`] indexed by reversed keys, for suffix-style queries such as word-break
+/// segmentation: "which stored keys end exactly here in this text?"
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SuffixTrie<K, V>
+where
+    K: Hash + Eq + Default,
+    V: Hash + Eq + Clone + Default,
+{
+    inner: Trie<K, V>,
+}
+
+impl<K, V> SuffixTrie<K, V>
+where
+    K: Hash + Eq + Default,
+    V: Hash + Eq + Clone + Default,
+{
+    /// Create empty SuffixTrie
+    pub fn new() -> Self {
+        SuffixTrie { inner: Trie::new() }
+    }
+
+    /// Inserts key reversed, so `query_suffixes` can find it while scanning text back-to-front
+    pub fn insert<I>(&mut self, key: I, value: V)
+    where
+        I: IntoIterator<Item = K>,
+        I::IntoIter: DoubleEndedIterator,
+    {
+        self.inner.insert(key.into_iter().rev(), value);
+    }
+
+    /// ### About
+    /// Consumes text in reverse and records the length (number of parts consumed) every time
+    /// the walk lands on a node with a value, stopping when no child matches. The returned
+    /// lengths identify all inserted keys that are suffixes of `text`, which a caller can feed
+    /// into a word-break DP recurrence (`dp[i] = min over matches of dp[i - len]`) to segment
+    /// text with minimal leftover.
+    pub fn query_suffixes<I>(&self, text: I) -> Vec<usize>
+    where
+        I: IntoIterator<Item = K>,
+        I::IntoIter: DoubleEndedIterator,
+    {
+        self.inner.traverse_lengths(text.into_iter().rev())
+    }
+}
+
+impl<K, V> Default for SuffixTrie<K, V>
+where
+    K: Hash + Eq + Default,
+    V: Hash + Eq + Clone + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::{Path, PathBuf};
@@ -149,4 +504,284 @@ mod tests {
         );
         assert!(!trie.contains(path_to_key_iter(src_path2)));
     }
+
+    #[test]
+    fn test_find_prefixes() {
+        let mut trie: Trie<String, String> = Trie::new();
+        trie.insert(
+            vec!["four", "score", "and"].into_iter().map(String::from),
+            "seven".to_string(),
+        );
+        trie.insert(
+            vec!["four", "score", "and", "seven"]
+                .into_iter()
+                .map(String::from),
+            "years".to_string(),
+        );
+
+        let result = trie.find_prefixes(
+            vec!["four", "score", "and", "seven", "years", "ago"]
+                .into_iter()
+                .map(String::from),
+        );
+
+        assert_eq!(result, vec!["seven".to_string(), "years".to_string()]);
+    }
+
+    #[test]
+    fn test_predictive_search() {
+        let mut trie: Trie<String, String> = Trie::new();
+        trie.insert(
+            vec!["four", "score", "and"].into_iter().map(String::from),
+            "seven".to_string(),
+        );
+        trie.insert(
+            vec!["four", "score", "and", "seven"]
+                .into_iter()
+                .map(String::from),
+            "years".to_string(),
+        );
+
+        let mut result = trie.predictive_search(
+            vec!["four", "score", "and"].into_iter().map(String::from),
+        );
+        result.sort();
+
+        let mut expected = vec![
+            (
+                vec!["four", "score", "and"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                "seven".to_string(),
+            ),
+            (
+                vec!["four", "score", "and", "seven"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                "years".to_string(),
+            ),
+        ];
+        expected.sort();
+
+        assert_eq!(result, expected);
+        assert!(trie
+            .predictive_search(vec!["nope"].into_iter().map(String::from))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_iter_and_seek_prefix() {
+        let mut trie: Trie<String, String> = Trie::new();
+        trie.insert(
+            vec!["four", "score", "and"].into_iter().map(String::from),
+            "seven".to_string(),
+        );
+        trie.insert(
+            vec!["four", "score", "and", "seven"]
+                .into_iter()
+                .map(String::from),
+            "years".to_string(),
+        );
+        trie.insert(
+            vec!["other"].into_iter().map(String::from),
+            "branch".to_string(),
+        );
+
+        let mut all: Vec<(Vec<String>, String)> = trie.iter().collect();
+        all.sort();
+        let mut expected_all = vec![
+            (
+                vec!["four", "score", "and"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                "seven".to_string(),
+            ),
+            (
+                vec!["four", "score", "and", "seven"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                "years".to_string(),
+            ),
+            (
+                vec!["other"].into_iter().map(String::from).collect(),
+                "branch".to_string(),
+            ),
+        ];
+        expected_all.sort();
+        assert_eq!(all, expected_all);
+
+        let mut seeked: Vec<(Vec<String>, String)> = trie
+            .seek_prefix(vec!["four", "score", "and"].into_iter().map(String::from))
+            .collect();
+        seeked.sort();
+        let mut expected_seeked = expected_all[..2].to_vec();
+        expected_seeked.sort();
+        assert_eq!(seeked, expected_seeked);
+
+        assert!(trie
+            .seek_prefix(vec!["nope"].into_iter().map(String::from))
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_wildcard_routing() {
+        let mut trie: Trie<String, String> =
+            Trie::new().with_wildcard("*".to_string());
+        trie.insert(
+            vec!["users", "*", "profile"].into_iter().map(String::from),
+            "profile_handler".to_string(),
+        );
+
+        assert_eq!(
+            trie.best_match(
+                vec!["users", "42", "profile"]
+                    .into_iter()
+                    .map(String::from)
+            ),
+            Some("profile_handler".to_string())
+        );
+
+        let (value, params) = trie
+            .get_with_params(
+                vec!["users", "42", "profile"]
+                    .into_iter()
+                    .map(String::from),
+            )
+            .unwrap();
+        assert_eq!(value, "profile_handler".to_string());
+        assert_eq!(params, vec!["42".to_string()]);
+
+        assert!(trie
+            .get_with_params(vec!["users", "42"].into_iter().map(String::from))
+            .is_none());
+    }
+
+    #[test]
+    fn test_wildcard_exact_child_takes_precedence() {
+        let mut trie: Trie<String, String> =
+            Trie::new().with_wildcard("*".to_string());
+        trie.insert(
+            vec!["users", "*", "profile"].into_iter().map(String::from),
+            "wildcard_handler".to_string(),
+        );
+        trie.insert(
+            vec!["users", "42", "profile"].into_iter().map(String::from),
+            "literal_handler".to_string(),
+        );
+
+        assert_eq!(
+            trie.best_match(
+                vec!["users", "42", "profile"]
+                    .into_iter()
+                    .map(String::from)
+            ),
+            Some("literal_handler".to_string())
+        );
+
+        let (value, params) = trie
+            .get_with_params(
+                vec!["users", "42", "profile"]
+                    .into_iter()
+                    .map(String::from),
+            )
+            .unwrap();
+        assert_eq!(value, "literal_handler".to_string());
+        // The "42" part matched the literal child, so it wasn't captured as a wildcard param.
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_suffix_trie_query_suffixes() {
+        let mut trie: SuffixTrie<char, ()> = SuffixTrie::new();
+        trie.insert("cat".chars(), ());
+        trie.insert("at".chars(), ());
+        trie.insert("cats".chars(), ());
+        trie.insert("s".chars(), ());
+
+        let mut lengths = trie.query_suffixes("cats".chars());
+        lengths.sort();
+        assert_eq!(lengths, vec![1, 4]);
+
+        assert!(trie.query_suffixes("dog".chars()).is_empty());
+    }
+
+    #[test]
+    fn test_remove_prunes_dead_branches() {
+        let mut trie: Trie<String, String> = Trie::new();
+        trie.insert(
+            vec!["four", "score", "and"].into_iter().map(String::from),
+            "seven".to_string(),
+        );
+        trie.insert(
+            vec!["four", "score", "and", "seven"]
+                .into_iter()
+                .map(String::from),
+            "years".to_string(),
+        );
+
+        assert_eq!(
+            trie.remove(
+                vec!["four", "score", "and", "seven"]
+                    .into_iter()
+                    .map(String::from)
+            ),
+            Some("years".to_string())
+        );
+        assert!(!trie.contains(
+            vec!["four", "score", "and", "seven"]
+                .into_iter()
+                .map(String::from)
+        ));
+        // The ancestor still holds its own value, so it and its path must survive.
+        assert!(trie.contains(vec!["four", "score", "and"].into_iter().map(String::from)));
+
+        assert_eq!(
+            trie.remove(vec!["four", "score", "and"].into_iter().map(String::from)),
+            Some("seven".to_string())
+        );
+        // No values remain anywhere under "four", so the whole branch is pruned.
+        assert!(trie
+            .predictive_search(vec!["four"].into_iter().map(String::from))
+            .is_empty());
+        assert!(trie
+            .remove(vec!["nope"].into_iter().map(String::from))
+            .is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut trie: Trie<String, String> = Trie::new();
+        trie.insert(
+            vec!["four", "score", "and"].into_iter().map(String::from),
+            "seven".to_string(),
+        );
+        trie.insert(
+            vec!["four", "score", "and", "seven"]
+                .into_iter()
+                .map(String::from),
+            "years".to_string(),
+        );
+
+        let json = serde_json::to_string(&trie).unwrap();
+        let restored: Trie<String, String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            restored.get(vec!["four", "score", "and"].into_iter().map(String::from)),
+            Some("seven".to_string())
+        );
+        assert_eq!(
+            restored.best_match(
+                vec!["four", "score", "and", "seven", "years", "ago"]
+                    .into_iter()
+                    .map(String::from)
+            ),
+            Some("years".to_string())
+        );
+    }
 }